@@ -1,20 +1,27 @@
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Local, TimeZone};
+use futures::future::join_all;
 use log::warn;
 use reqwest::Client;
-use serde::Serialize;
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
+    sync::{Mutex, OnceLock},
     time::Duration,
 };
+use tokio::sync::Mutex as AsyncMutex;
 
 use crate::opencode_auth;
 
 const OPENCODE_CONFIG_DIR: &str = ".config/opencode";
 const OPENCODE_DATA_DIR: &str = ".local/share/opencode";
 
+const QUOTA_CACHE_FILE: &str = "quota-cache.json";
+const QUOTA_CACHE_TTL_SECONDS: i64 = 60;
+
 const GOOGLE_CLIENT_ID: &str =
     "1071006060591-tmhssin2h21lcre235vtolojh4g403ep.apps.googleusercontent.com";
 const GOOGLE_CLIENT_SECRET: &str = "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";
@@ -31,25 +38,68 @@ const GOOGLE_USER_AGENT: &str = "antigravity/1.11.5 windows/amd64";
 const GOOGLE_API_CLIENT: &str = "google-cloud-sdk vscode_cloudshelleditor/0.1";
 const GOOGLE_CLIENT_METADATA: &str =
     "{\"ideType\":\"IDE_UNSPECIFIED\",\"platform\":\"PLATFORM_UNSPECIFIED\",\"pluginType\":\"GEMINI\"}";
+const GOOGLE_CLOUD_PLATFORM_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Default)]
 struct AuthEntry {
-    token: Option<String>,
-    access: Option<String>,
-    refresh: Option<String>,
+    token: Option<Secret<String>>,
+    access: Option<Secret<String>>,
+    refresh: Option<Secret<String>>,
     expires: Option<i64>,
-    key: Option<String>,
+    key: Option<Secret<String>>,
+}
+
+impl std::fmt::Debug for AuthEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthEntry")
+            .field("token", &self.token.as_ref().map(|_| "[REDACTED]"))
+            .field("access", &self.access.as_ref().map(|_| "[REDACTED]"))
+            .field("refresh", &self.refresh.as_ref().map(|_| "[REDACTED]"))
+            .field("expires", &self.expires)
+            .field("key", &self.key.as_ref().map(|_| "[REDACTED]"))
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+struct ServiceAccountAuth {
+    client_email: String,
+    private_key: Secret<String>,
+    token_uri: String,
 }
 
-#[derive(Clone, Debug, Default)]
+impl std::fmt::Debug for ServiceAccountAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAccountAuth")
+            .field("client_email", &self.client_email)
+            .field("private_key", &"[REDACTED]")
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+#[derive(Clone, Default)]
 struct GoogleAuth {
-    access_token: Option<String>,
-    refresh_token: Option<String>,
+    access_token: Option<Secret<String>>,
+    refresh_token: Option<Secret<String>>,
     expires: Option<i64>,
     project_id: Option<String>,
+    service_account: Option<ServiceAccountAuth>,
+}
+
+impl std::fmt::Debug for GoogleAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GoogleAuth")
+            .field("access_token", &self.access_token.as_ref().map(|_| "[REDACTED]"))
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| "[REDACTED]"))
+            .field("expires", &self.expires)
+            .field("project_id", &self.project_id)
+            .field("service_account", &self.service_account)
+            .finish()
+    }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ProviderResult {
     provider_id: String,
@@ -60,9 +110,19 @@ pub struct ProviderResult {
     error: Option<String>,
     usage: Option<ProviderUsage>,
     fetched_at: i64,
+    /// Whether this failure is worth retrying. OpenAI and z.ai set it for a
+    /// connection error or HTTP 429/5xx only; Google sets it for any failure to
+    /// obtain an access token or the model list, without telling a 4xx response
+    /// apart from a connection error or 429/5xx.
+    /// Not sent to the frontend; consulted only by `fetch_provider_with_retry`'s
+    /// retry loop.
+    #[serde(skip, default)]
+    retryable: bool,
+    #[serde(skip, default)]
+    retry_after_ms: Option<u64>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct ProviderUsage {
     windows: HashMap<String, UsageWindow>,
@@ -70,7 +130,7 @@ struct ProviderUsage {
     models: Option<HashMap<String, ProviderUsage>>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct UsageWindow {
     used_percent: Option<f64>,
@@ -129,14 +189,14 @@ fn normalize_auth_entry(value: Option<&Value>) -> Option<AuthEntry> {
     let value = value?;
     match value {
         Value::String(token) => Some(AuthEntry {
-            token: Some(token.clone()),
+            token: Some(Secret::new(token.clone())),
             ..AuthEntry::default()
         }),
         Value::Object(map) => {
-            let token = map.get("token").and_then(|v| v.as_str()).map(|s| s.to_string());
-            let access = map.get("access").and_then(|v| v.as_str()).map(|s| s.to_string());
-            let refresh = map.get("refresh").and_then(|v| v.as_str()).map(|s| s.to_string());
-            let key = map.get("key").and_then(|v| v.as_str()).map(|s| s.to_string());
+            let token = map.get("token").and_then(|v| v.as_str()).map(|s| Secret::new(s.to_string()));
+            let access = map.get("access").and_then(|v| v.as_str()).map(|s| Secret::new(s.to_string()));
+            let refresh = map.get("refresh").and_then(|v| v.as_str()).map(|s| Secret::new(s.to_string()));
+            let key = map.get("key").and_then(|v| v.as_str()).map(|s| Secret::new(s.to_string()));
             let expires = map
                 .get("expires")
                 .and_then(|v| v.as_i64())
@@ -207,9 +267,29 @@ fn build_result(
         error,
         usage,
         fetched_at: chrono::Utc::now().timestamp_millis(),
+        retryable: false,
+        retry_after_ms: None,
     }
 }
 
+fn mark_retryable(mut result: ProviderResult, retryable: bool, retry_after_ms: Option<u64>) -> ProviderResult {
+    result.retryable = retryable;
+    result.retry_after_ms = retry_after_ms;
+    result
+}
+
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status.as_u16() == 429 || status.is_server_error()
+}
+
+fn parse_retry_after_ms(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(|seconds| seconds * 1000)
+}
+
 async fn load_auth_map() -> Result<serde_json::Map<String, Value>> {
     let auth = opencode_auth::read_auth().await?;
     auth.as_object()
@@ -243,7 +323,11 @@ pub async fn list_configured_quota_providers() -> Result<Vec<String>> {
         }
     }
 
-    let google_auth = normalize_auth_entry(get_auth_entry(&auth, &["google", "antigravity"]));
+    let google_raw = get_auth_entry(&auth, &["google", "antigravity"]);
+    if google_raw.and_then(extract_service_account).is_some() {
+        configured.insert("google".to_string());
+    }
+    let google_auth = normalize_auth_entry(google_raw);
     if let Some(entry) = google_auth {
         if entry.access.is_some() || entry.token.is_some() || entry.refresh.is_some() {
             configured.insert("google".to_string());
@@ -290,7 +374,7 @@ async fn fetch_openai_quota(client: &Client) -> Result<ProviderResult> {
 
     let response = client
         .get("https://chatgpt.com/backend-api/wham/usage")
-        .bearer_auth(access_token)
+        .bearer_auth(access_token.expose_secret())
         .header("Content-Type", "application/json")
         .send()
         .await;
@@ -298,25 +382,29 @@ async fn fetch_openai_quota(client: &Client) -> Result<ProviderResult> {
     let response = match response {
         Ok(resp) => resp,
         Err(err) => {
-            return Ok(build_result(
-                "openai",
-                "OpenAI",
-                false,
+            return Ok(mark_retryable(
+                build_result("openai", "OpenAI", false, true, None, Some(err.to_string())),
                 true,
                 None,
-                Some(err.to_string()),
             ))
         }
     };
 
     if !response.status().is_success() {
-        return Ok(build_result(
-            "openai",
-            "OpenAI",
-            false,
-            true,
-            None,
-            Some(format!("API error: {}", response.status().as_u16())),
+        let status = response.status();
+        let retryable = is_retryable_status(status);
+        let retry_after_ms = retryable.then(|| parse_retry_after_ms(response.headers())).flatten();
+        return Ok(mark_retryable(
+            build_result(
+                "openai",
+                "OpenAI",
+                false,
+                true,
+                None,
+                Some(format!("API error: {}", status.as_u16())),
+            ),
+            retryable,
+            retry_after_ms,
         ));
     }
 
@@ -386,16 +474,37 @@ async fn fetch_openai_quota(client: &Client) -> Result<ProviderResult> {
     ))
 }
 
+fn extract_service_account(value: &Value) -> Option<ServiceAccountAuth> {
+    let map = value.as_object()?;
+    let client_email = map.get("client_email")?.as_str()?.to_string();
+    let private_key = Secret::new(map.get("private_key")?.as_str()?.to_string());
+    let token_uri = map.get("token_uri")?.as_str()?.to_string();
+    Some(ServiceAccountAuth {
+        client_email,
+        private_key,
+        token_uri,
+    })
+}
+
 async fn resolve_google_auth() -> Result<Option<GoogleAuth>> {
     let auth = load_auth_map().await?;
-    let entry = normalize_auth_entry(get_auth_entry(&auth, &["google", "antigravity"]));
+    let raw = get_auth_entry(&auth, &["google", "antigravity"]);
+
+    if let Some(service_account) = raw.and_then(extract_service_account) {
+        return Ok(Some(GoogleAuth {
+            service_account: Some(service_account),
+            ..GoogleAuth::default()
+        }));
+    }
+
+    let entry = normalize_auth_entry(raw);
 
     if let Some(entry) = entry {
         let mut refresh = entry.refresh.clone();
         let mut project_id = None;
-        if let Some(value) = entry.refresh.clone() {
-            if let Some((first, second)) = value.split_once('|') {
-                refresh = Some(first.to_string());
+        if let Some(value) = entry.refresh.as_ref() {
+            if let Some((first, second)) = value.expose_secret().split_once('|') {
+                refresh = Some(Secret::new(first.to_string()));
                 project_id = Some(second.to_string());
             }
         }
@@ -404,6 +513,7 @@ async fn resolve_google_auth() -> Result<Option<GoogleAuth>> {
             refresh_token: refresh,
             expires: entry.expires,
             project_id,
+            service_account: None,
         }));
     }
 
@@ -427,7 +537,7 @@ async fn resolve_google_auth() -> Result<Option<GoogleAuth>> {
                 let refresh_token = account
                     .get("refreshToken")
                     .and_then(|value| value.as_str())
-                    .map(|value| value.to_string());
+                    .map(|value| Secret::new(value.to_string()));
                 if refresh_token.is_none() {
                     continue;
                 }
@@ -446,6 +556,7 @@ async fn resolve_google_auth() -> Result<Option<GoogleAuth>> {
                     refresh_token,
                     expires: None,
                     project_id,
+                    service_account: None,
                 }));
             }
         }
@@ -454,7 +565,10 @@ async fn resolve_google_auth() -> Result<Option<GoogleAuth>> {
     Ok(None)
 }
 
-async fn refresh_google_access_token(client: &Client, refresh_token: &str) -> Result<Option<String>> {
+async fn refresh_google_access_token(
+    client: &Client,
+    refresh_token: &str,
+) -> Result<Option<(String, i64)>> {
     let body = format!(
         "client_id={}&client_secret={}&refresh_token={}&grant_type=refresh_token",
         urlencoding::encode(GOOGLE_CLIENT_ID),
@@ -482,10 +596,160 @@ async fn refresh_google_access_token(client: &Client, refresh_token: &str) -> Re
     }
 
     let payload: Value = response.json().await.unwrap_or(Value::Null);
-    Ok(payload
+    let access_token = payload
+        .get("access_token")
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string());
+    let expires_in = payload
+        .get("expires_in")
+        .and_then(|value| value.as_i64())
+        .unwrap_or(3600);
+
+    Ok(access_token.map(|token| (token, expires_in)))
+}
+
+/// Merges a freshly refreshed access token and its expiry into an existing
+/// `google`/`antigravity` auth entry, preserving whatever `refresh` credential
+/// is already persisted. When the resolved auth came solely from the
+/// antigravity-accounts file (no `refresh` persisted yet), the refresh token
+/// and project id are carried forward so they aren't lost once the written
+/// `google`/`antigravity` entry starts shadowing that file in
+/// `resolve_google_auth`.
+fn merge_google_auth_entry(
+    existing: Option<&serde_json::Map<String, Value>>,
+    auth: &GoogleAuth,
+    access_token: &str,
+    expires: i64,
+) -> serde_json::Map<String, Value> {
+    let mut entry = existing.cloned().unwrap_or_default();
+
+    entry.insert("access".to_string(), Value::String(access_token.to_string()));
+    entry.insert("expires".to_string(), Value::from(expires));
+
+    if !entry.contains_key("refresh") {
+        if let Some(refresh_token) = auth.refresh_token.as_ref() {
+            let refresh = match auth.project_id.as_ref() {
+                Some(project_id) => format!("{}|{}", refresh_token.expose_secret(), project_id),
+                None => refresh_token.expose_secret().clone(),
+            };
+            entry.insert("refresh".to_string(), Value::String(refresh));
+        }
+    }
+
+    entry
+}
+
+/// Persists a freshly refreshed access token and its expiry back into the
+/// `google`/`antigravity` auth entry so the next quota poll can skip the
+/// OAuth round-trip while it is still valid.
+async fn persist_google_access_token(auth: &GoogleAuth, access_token: &str, expires_in: i64) -> Result<()> {
+    let mut auth_map = load_auth_map().await?;
+    let expires = chrono::Utc::now().timestamp_millis() + expires_in * 1000;
+
+    let key = if auth_map.contains_key("google") {
+        "google"
+    } else {
+        "antigravity"
+    };
+
+    let entry = merge_google_auth_entry(
+        auth_map.get(key).and_then(|value| value.as_object()),
+        auth,
+        access_token,
+        expires,
+    );
+
+    auth_map.insert(key.to_string(), Value::Object(entry));
+    opencode_auth::write_auth(Value::Object(auth_map)).await
+}
+
+fn service_account_token_cache() -> &'static Mutex<HashMap<String, (Secret<String>, i64)>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (Secret<String>, i64)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Identifies which minted token belongs to which service account, so that
+/// swapping the configured credential at runtime can't serve a stale token
+/// minted for the previous account.
+fn service_account_cache_key(service_account: &ServiceAccountAuth) -> String {
+    format!("{}:{}", service_account.client_email, service_account.token_uri)
+}
+
+async fn mint_service_account_token(
+    client: &Client,
+    service_account: &ServiceAccountAuth,
+) -> Result<Option<Secret<String>>> {
+    let now = chrono::Utc::now().timestamp();
+    let cache_key = service_account_cache_key(service_account);
+
+    if let Some((token, expires_at)) = service_account_token_cache()
+        .lock()
+        .unwrap()
+        .get(&cache_key)
+        .cloned()
+    {
+        if expires_at > now {
+            return Ok(Some(token));
+        }
+    }
+
+    let header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+    let claims = serde_json::json!({
+        "iss": service_account.client_email,
+        "scope": GOOGLE_CLOUD_PLATFORM_SCOPE,
+        "aud": service_account.token_uri,
+        "iat": now,
+        "exp": now + 3600,
+    });
+    let encoding_key =
+        jsonwebtoken::EncodingKey::from_rsa_pem(service_account.private_key.expose_secret().as_bytes())
+            .map_err(|err| anyhow!("Invalid service-account private key: {}", err))?;
+    let assertion = jsonwebtoken::encode(&header, &claims, &encoding_key)
+        .map_err(|err| anyhow!("Failed to sign service-account JWT: {}", err))?;
+
+    let body = format!(
+        "grant_type={}&assertion={}",
+        urlencoding::encode("urn:ietf:params:oauth:grant-type:jwt-bearer"),
+        urlencoding::encode(&assertion)
+    );
+
+    let response = client
+        .post(&service_account.token_uri)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(body)
+        .send()
+        .await;
+
+    let response = match response {
+        Ok(resp) => resp,
+        Err(err) => {
+            warn!("Failed to mint service-account access token: {}", err);
+            return Ok(None);
+        }
+    };
+
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+
+    let payload: Value = response.json().await.unwrap_or(Value::Null);
+    let access_token = payload
         .get("access_token")
         .and_then(|value| value.as_str())
-        .map(|value| value.to_string()))
+        .map(|value| Secret::new(value.to_string()));
+    let expires_in = payload
+        .get("expires_in")
+        .and_then(|value| value.as_i64())
+        .unwrap_or(3600);
+
+    if let Some(token) = access_token.as_ref() {
+        service_account_token_cache()
+            .lock()
+            .unwrap()
+            .insert(cache_key, (token.clone(), now + expires_in));
+    }
+
+    Ok(access_token)
 }
 
 async fn fetch_google_models(client: &Client, access_token: &str, project_id: Option<&str>) -> Option<Value> {
@@ -552,8 +816,10 @@ async fn fetch_google_quota(client: &Client) -> Result<ProviderResult> {
     };
 
     let now = chrono::Utc::now().timestamp_millis();
-    let mut access_token = auth.access_token;
-    if access_token.is_none()
+    let mut access_token = auth.access_token.clone();
+    if let Some(service_account) = auth.service_account.as_ref() {
+        access_token = mint_service_account_token(client, service_account).await?;
+    } else if access_token.is_none()
         || auth
             .expires
             .is_some_and(|expires| expires <= now)
@@ -568,30 +834,46 @@ async fn fetch_google_quota(client: &Client) -> Result<ProviderResult> {
                 Some("Missing refresh token".to_string()),
             ));
         };
-        access_token = refresh_google_access_token(client, refresh_token).await?;
+        access_token = match refresh_google_access_token(client, refresh_token.expose_secret()).await? {
+            Some((token, expires_in)) => {
+                if let Err(err) = persist_google_access_token(&auth, &token, expires_in).await {
+                    warn!("Failed to persist refreshed Google access token: {}", err);
+                }
+                Some(Secret::new(token))
+            }
+            None => None,
+        };
     }
 
     let Some(access_token) = access_token else {
-        return Ok(build_result(
-            "google",
-            "Google",
-            false,
+        return Ok(mark_retryable(
+            build_result(
+                "google",
+                "Google",
+                false,
+                true,
+                None,
+                Some("Failed to refresh OAuth token".to_string()),
+            ),
             true,
             None,
-            Some("Failed to refresh OAuth token".to_string()),
         ));
     };
 
     let project_id = auth.project_id.unwrap_or_else(|| DEFAULT_PROJECT_ID.to_string());
-    let payload = fetch_google_models(client, &access_token, Some(project_id.as_str())).await;
+    let payload = fetch_google_models(client, access_token.expose_secret(), Some(project_id.as_str())).await;
     let Some(payload) = payload else {
-        return Ok(build_result(
-            "google",
-            "Google",
-            false,
+        return Ok(mark_retryable(
+            build_result(
+                "google",
+                "Google",
+                false,
+                true,
+                None,
+                Some("Failed to fetch models".to_string()),
+            ),
             true,
             None,
-            Some("Failed to fetch models".to_string()),
         ));
     };
 
@@ -689,7 +971,7 @@ async fn fetch_zai_quota(client: &Client) -> Result<ProviderResult> {
 
     let response = client
         .get("https://api.z.ai/api/monitor/usage/quota/limit")
-        .bearer_auth(api_key)
+        .bearer_auth(api_key.expose_secret())
         .header("Content-Type", "application/json")
         .send()
         .await;
@@ -697,25 +979,29 @@ async fn fetch_zai_quota(client: &Client) -> Result<ProviderResult> {
     let response = match response {
         Ok(resp) => resp,
         Err(err) => {
-            return Ok(build_result(
-                "zai-coding-plan",
-                "z.ai",
-                false,
+            return Ok(mark_retryable(
+                build_result("zai-coding-plan", "z.ai", false, true, None, Some(err.to_string())),
                 true,
                 None,
-                Some(err.to_string()),
             ))
         }
     };
 
     if !response.status().is_success() {
-        return Ok(build_result(
-            "zai-coding-plan",
-            "z.ai",
-            false,
-            true,
-            None,
-            Some(format!("API error: {}", response.status().as_u16())),
+        let status = response.status();
+        let retryable = is_retryable_status(status);
+        let retry_after_ms = retryable.then(|| parse_retry_after_ms(response.headers())).flatten();
+        return Ok(mark_retryable(
+            build_result(
+                "zai-coding-plan",
+                "z.ai",
+                false,
+                true,
+                None,
+                Some(format!("API error: {}", status.as_u16())),
+            ),
+            retryable,
+            retry_after_ms,
         ));
     }
 
@@ -769,8 +1055,68 @@ async fn fetch_zai_quota(client: &Client) -> Result<ProviderResult> {
     ))
 }
 
-pub async fn fetch_quota_for_provider(client: &Client, provider_id: &str) -> Result<ProviderResult> {
-    match provider_id {
+#[derive(Serialize, Deserialize, Clone)]
+struct CachedQuotaEntry {
+    result: ProviderResult,
+    fetched_at: i64,
+}
+
+fn quota_cache_path() -> PathBuf {
+    opencode_data_dir().join(QUOTA_CACHE_FILE)
+}
+
+/// Guards the on-disk cache's read-modify-write cycle. `fetch_all_quotas`
+/// drives every provider concurrently, and without this lock two providers
+/// finishing around the same time would each read the same stale snapshot
+/// and the last write would clobber the other's freshly-cached entry.
+fn quota_cache_lock() -> &'static AsyncMutex<()> {
+    static LOCK: OnceLock<AsyncMutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| AsyncMutex::new(()))
+}
+
+async fn read_quota_cache() -> HashMap<String, CachedQuotaEntry> {
+    let path = quota_cache_path();
+    match read_json_file(&path).await {
+        Some(value) => serde_json::from_value(value).unwrap_or_default(),
+        None => HashMap::new(),
+    }
+}
+
+async fn write_quota_cache(cache: &HashMap<String, CachedQuotaEntry>) {
+    let path = quota_cache_path();
+    if let Some(parent) = path.parent() {
+        if let Err(err) = tokio::fs::create_dir_all(parent).await {
+            warn!("Failed to create quota cache dir {}: {}", parent.display(), err);
+            return;
+        }
+    }
+
+    match serde_json::to_vec_pretty(cache) {
+        Ok(bytes) => {
+            if let Err(err) = tokio::fs::write(&path, bytes).await {
+                warn!("Failed to write quota cache {}: {}", path.display(), err);
+            }
+        }
+        Err(err) => warn!("Failed to serialize quota cache: {}", err),
+    }
+}
+
+pub async fn fetch_quota_for_provider(
+    client: &Client,
+    provider_id: &str,
+    force_refresh: bool,
+) -> Result<ProviderResult> {
+    if !force_refresh {
+        let cache = read_quota_cache().await;
+        if let Some(entry) = cache.get(provider_id) {
+            let age_seconds = (chrono::Utc::now().timestamp_millis() - entry.fetched_at) / 1000;
+            if age_seconds < QUOTA_CACHE_TTL_SECONDS {
+                return Ok(entry.result.clone());
+            }
+        }
+    }
+
+    let result = match provider_id {
         "openai" => fetch_openai_quota(client).await,
         "google" => fetch_google_quota(client).await,
         "zai-coding-plan" => fetch_zai_quota(client).await,
@@ -782,5 +1128,111 @@ pub async fn fetch_quota_for_provider(client: &Client, provider_id: &str) -> Res
             None,
             Some("Unsupported provider".to_string()),
         )),
+    }?;
+
+    if result.ok {
+        let _guard = quota_cache_lock().lock().await;
+        let mut cache = read_quota_cache().await;
+        cache.insert(
+            provider_id.to_string(),
+            CachedQuotaEntry {
+                result: result.clone(),
+                fetched_at: result.fetched_at,
+            },
+        );
+        write_quota_cache(&cache).await;
+    }
+
+    Ok(result)
+}
+
+const RETRY_BASE_DELAYS_MS: [u64; 3] = [200, 400, 800];
+
+fn jitter_ms() -> u64 {
+    (chrono::Utc::now().timestamp_subsec_nanos() % 100) as u64
+}
+
+async fn fetch_provider_with_retry(client: &Client, provider_id: &str) -> ProviderResult {
+    let fetch = |force_refresh: bool| async move {
+        fetch_quota_for_provider(client, provider_id, force_refresh)
+            .await
+            .unwrap_or_else(|err| build_result(provider_id, provider_id, false, true, None, Some(err.to_string())))
+    };
+
+    let mut result = fetch(false).await;
+
+    for (attempt, base_delay_ms) in RETRY_BASE_DELAYS_MS.iter().enumerate() {
+        if result.ok || !result.retryable {
+            break;
+        }
+
+        let delay_ms = result.retry_after_ms.unwrap_or(*base_delay_ms) + jitter_ms();
+        warn!(
+            "Retrying {} quota fetch in {}ms (attempt {}/{})",
+            provider_id,
+            delay_ms,
+            attempt + 2,
+            RETRY_BASE_DELAYS_MS.len() + 1
+        );
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+
+        result = fetch(true).await;
+    }
+
+    result
+}
+
+/// Fetches every configured provider's quota concurrently. A flaky endpoint
+/// is retried with jittered exponential backoff instead of blocking or
+/// failing the whole dashboard refresh.
+pub async fn fetch_all_quotas(client: &Client) -> Vec<ProviderResult> {
+    let provider_ids = match list_configured_quota_providers().await {
+        Ok(ids) => ids,
+        Err(err) => {
+            warn!("Failed to list configured quota providers: {}", err);
+            return Vec::new();
+        }
+    };
+
+    join_all(
+        provider_ids
+            .iter()
+            .map(|provider_id| fetch_provider_with_retry(client, provider_id)),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_google_auth_entry_carries_refresh_and_project_id_forward_from_accounts_file() {
+        let auth = GoogleAuth {
+            access_token: None,
+            refresh_token: Some(Secret::new("rt-123".to_string())),
+            expires: None,
+            project_id: Some("my-project".to_string()),
+            service_account: None,
+        };
+
+        let first = merge_google_auth_entry(None, &auth, "access-1", 1_000);
+        assert_eq!(first.get("access").and_then(|v| v.as_str()), Some("access-1"));
+        assert_eq!(first.get("expires").and_then(|v| v.as_i64()), Some(1_000));
+        assert_eq!(
+            first.get("refresh").and_then(|v| v.as_str()),
+            Some("rt-123|my-project")
+        );
+
+        // A second refresh, as happens once the token from the first refresh
+        // expires, must not clobber the refresh token / project id that were
+        // only ever available from the antigravity-accounts file.
+        let second = merge_google_auth_entry(Some(&first), &auth, "access-2", 2_000);
+        assert_eq!(second.get("access").and_then(|v| v.as_str()), Some("access-2"));
+        assert_eq!(second.get("expires").and_then(|v| v.as_i64()), Some(2_000));
+        assert_eq!(
+            second.get("refresh").and_then(|v| v.as_str()),
+            Some("rt-123|my-project")
+        );
     }
 }